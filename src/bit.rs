@@ -0,0 +1,229 @@
+// This approach keeps a 2D Fenwick tree (Binary Indexed Tree) alongside the
+// matrix so that individual cells can be mutated without rebuilding the
+// whole cumulative-sum table.
+//
+// A point update at (x, y) touches O(log nrows * log ncols) tree nodes,
+// and a rectangle sum query costs the same by combining four prefix
+// queries through the usual inclusion-exclusion.
+
+#[cfg(feature = "csv-fallback")]
+use csv;
+#[cfg(feature = "csv-fallback")]
+use serde::Deserialize;
+use std::error::Error;
+use std::fs::File;
+#[cfg(not(feature = "csv-fallback"))]
+use std::io::{BufRead, BufReader};
+
+#[derive(Debug)]
+pub struct Matrix {
+    nrows: usize,
+    ncols: usize,
+    elems: Vec<Vec<i64>>,
+    tree: Vec<Vec<i64>>,
+}
+
+// Row of a matrix containing all the columns within that row.
+// Deserialized from a CSV record.
+#[cfg(feature = "csv-fallback")]
+#[derive(Debug, Deserialize, Eq, PartialEq, Clone)]
+struct Row {
+    cols: Vec<i64>,
+}
+
+impl Matrix {
+    // new reads a dense CSV file into a matrix, parsing it directly off a
+    // buffered byte stream instead of going through the `csv` crate. Each
+    // line is read into a single reused buffer via `read_line` (so no fresh
+    // `String` is allocated per row) and split on commas into field slices,
+    // which are parsed with `str::parse` directly (no intermediate `String`
+    // allocation per field). Each row's parsed values still go into their
+    // own newly allocated `Vec`. This only handles plain, unquoted fields;
+    // build with the `csv-fallback` feature for quoted or escaped fields.
+    #[cfg(not(feature = "csv-fallback"))]
+    pub fn new(filepath: &str) -> Result<Self, Box<dyn Error>> {
+        let file = File::open(filepath)?;
+        let mut reader = BufReader::new(file);
+
+        let mut elems: Vec<Vec<i64>> = Vec::new();
+        let mut ncols = 0;
+        let mut buf = String::new();
+
+        loop {
+            buf.clear();
+            if reader.read_line(&mut buf)? == 0 {
+                break;
+            }
+            let line = buf.trim_end_matches(['\r', '\n']);
+            if line.is_empty() {
+                continue;
+            }
+
+            let mut cols: Vec<i64> = Vec::new();
+            for field in line.split(',') {
+                cols.push(field.trim().parse()?);
+            }
+
+            let rl = cols.len();
+
+            // Cross validate that each read row from CSV has the same number
+            // of columns
+            if ncols == 0 {
+                ncols = rl
+            } else if ncols != rl {
+                return Err(format!("each row is expected to have same number of columns, previous rows had {ncols}, this row has {rl}"))?;
+            }
+
+            elems.push(cols)
+        }
+
+        Ok(Matrix::from_elems(elems, ncols))
+    }
+
+    // new reads the matrix from a CSV file given as input, going through the
+    // `csv` crate so quoted and escaped fields are handled correctly. Build
+    // with the `csv-fallback` feature to use this path instead of the
+    // allocation-light byte scanner.
+    #[cfg(feature = "csv-fallback")]
+    pub fn new(filepath: &str) -> Result<Self, Box<dyn Error>> {
+        // Read the CSV file record-by-record
+        let file = File::open(filepath)?;
+
+        let mut rdr = csv::ReaderBuilder::new()
+            .has_headers(false)
+            .from_reader(file);
+
+        // Initialize the main matrix
+        let mut elems: Vec<Vec<i64>> = Vec::new();
+
+        let mut ncols = 0;
+
+        // Read and deserialize each row from the CSV
+        for result in rdr.deserialize() {
+            let row: Row = result?;
+            let rl = row.cols.len();
+
+            // Cross validate that each read row from CSV has the same number
+            // of columns
+            if ncols == 0 {
+                ncols = rl
+            } else if ncols != rl {
+                return Err(format!("each row is expected to have same number of columns, previous rows had {ncols}, this row has {rl}"))?;
+            }
+
+            elems.push(row.cols)
+        }
+
+        Ok(Matrix::from_elems(elems, ncols))
+    }
+
+    // from_elems builds a matrix, and a Fenwick tree matching it, out of an
+    // already-assembled grid of elements by feeding every cell through `set`.
+    fn from_elems(elems: Vec<Vec<i64>>, ncols: usize) -> Self {
+        let nrows = elems.len();
+
+        let mut m = Matrix {
+            nrows: nrows,
+            ncols: ncols,
+            elems: vec![vec![0; ncols]; nrows],
+            tree: vec![vec![0; ncols + 1]; nrows + 1],
+        };
+
+        for y in 0..nrows {
+            for x in 0..ncols {
+                let value = elems[y][x];
+                m.set(x, y, value);
+            }
+        }
+
+        m
+    }
+
+    // set overwrites the value at (x, y) with the given value, propagating
+    // the delta between the new and the old value through the tree.
+    pub fn set(&mut self, x: usize, y: usize, value: i64) {
+        let delta = value - self.elems[y][x];
+        self.elems[y][x] = value;
+        self.add(x, y, delta);
+    }
+
+    // add applies delta to the value at (x, y), updating every tree node
+    // that covers (x, y).
+    pub fn add(&mut self, x: usize, y: usize, delta: i64) {
+        let mut i = y + 1;
+        while i <= self.nrows {
+            let mut j = x + 1;
+            while j <= self.ncols {
+                self.tree[i][j] += delta;
+                j += j & j.wrapping_neg();
+            }
+            i += i & i.wrapping_neg();
+        }
+    }
+
+    // prefix returns the sum of the rectangle from (0, 0) to (x, y)
+    // inclusive.
+    fn prefix(&self, x: usize, y: usize) -> i64 {
+        let mut sum: i64 = 0;
+
+        let mut i = y + 1;
+        while i > 0 {
+            let mut j = x + 1;
+            while j > 0 {
+                sum += self.tree[i][j];
+                j -= j & j.wrapping_neg();
+            }
+            i -= i & i.wrapping_neg();
+        }
+
+        sum
+    }
+
+    // sum returns the sum of all the elements of the matrix between the given
+    // coordinates.
+    pub fn sum(
+        &self,
+        startx: usize,
+        starty: usize,
+        endx: usize,
+        endy: usize,
+    ) -> Result<i64, String> {
+        // Validations for input coordinates.
+        //
+        // Because usize means unsigned startx and starty have to be greater
+        // than 0 and hence need not be checked.
+        if endx >= self.ncols {
+            return Err(format!(
+                "endx should be lesser than number of columns {}, got {endx}",
+                self.ncols - 1
+            ));
+        }
+        if endy >= self.nrows {
+            return Err(format!(
+                "endy should be lesser than number of rows {}, got {endy}",
+                self.nrows - 1
+            ));
+        }
+
+        let mut sum = self.prefix(endx, endy);
+        if startx > 0 {
+            sum -= self.prefix(startx - 1, endy)
+        }
+        if starty > 0 {
+            sum -= self.prefix(endx, starty - 1)
+        }
+        if startx > 0 && starty > 0 {
+            sum += self.prefix(startx - 1, starty - 1)
+        }
+
+        Ok(sum)
+    }
+}
+
+// 1, 2, 5, 11
+// 5, 9, 11, 15
+// 2, 17, 8, -10
+
+// 1  3   8   19
+// 6  17  33  59
+// 8  36  60  76
@@ -1,24 +1,87 @@
 mod allsum;
+mod bit;
 mod rowsum;
 
 fn main() {
     // Rowsum
     // Read the matrix from a CSV file given as input
-    let m = rowsum::Matrix::new("sample.csv").unwrap();
+    let m = rowsum::Matrix::<i64>::new("sample.csv").unwrap();
 
     // Compute the sum between given coordinates:
     // (startx, starty) -> (endx, endy)
     let s = m.sum(1, 1, 3, 2);
     println!("[RowSum method] Sum: {}", s.unwrap());
 
+    // Load the same matrix from a Matrix Market file instead of a CSV
+    let m = rowsum::Matrix::<i64>::from_matrix_market("sample.mtx").unwrap();
+    let s = m.sum(1, 1, 3, 2);
+    println!(
+        "[RowSum method] Sum loaded from a Matrix Market file: {}",
+        s.unwrap()
+    );
+
     println!("---------------------");
 
     // Allsum
     // Read the matrix from a CSV file given as input
-    let m = allsum::Matrix::new("sample.csv").unwrap();
+    let m = allsum::Matrix::<i64>::new("sample.csv").unwrap();
+
+    // Compute the sum between given coordinates:
+    // (startx, starty) -> (endx, endy)
+    let s = m.sum(1, 1, 3, 2);
+    println!("[Allsum method] Sum: {}", s.unwrap());
+
+    // Find the maximum-sum axis-aligned submatrix
+    let (maxsum, topleft, bottomright) = m.max_submatrix().unwrap();
+    println!("[Allsum method] Max submatrix sum: {maxsum}, from {topleft:?} to {bottomright:?}");
+
+    // Add the matrix to itself and re-query the sum
+    let doubled = allsum::Matrix::<i64>::new("sample.csv").unwrap() + m;
+    let s = doubled.sum(1, 1, 3, 2);
+    println!(
+        "[Allsum method] Sum after adding matrix to itself: {}",
+        s.unwrap()
+    );
+
+    // Subtract the original matrix back out and confirm the sum matches
+    let restored = doubled - allsum::Matrix::<i64>::new("sample.csv").unwrap();
+    let s = restored.sum(1, 1, 3, 2);
+    println!(
+        "[Allsum method] Sum after subtracting it back out: {}",
+        s.unwrap()
+    );
+
+    // Multiply the matrix by a 4x4 identity matrix; the sum should be
+    // unchanged since the shape stays 3x4
+    let identity = allsum::Matrix::<i64>::from_matrix_market("identity4.mtx").unwrap();
+    let unchanged = allsum::Matrix::<i64>::new("sample.csv").unwrap() * identity;
+    let s = unchanged.sum(1, 1, 3, 2);
+    println!(
+        "[Allsum method] Sum after multiplying by a 4x4 identity matrix: {}",
+        s.unwrap()
+    );
+
+    // Load the same matrix from a Matrix Market file instead of a CSV
+    let m = allsum::Matrix::<i64>::from_matrix_market("sample.mtx").unwrap();
+    let s = m.sum(1, 1, 3, 2);
+    println!(
+        "[Allsum method] Sum loaded from a Matrix Market file: {}",
+        s.unwrap()
+    );
+
+    println!("---------------------");
+
+    // Bit
+    // Read the matrix from a CSV file given as input
+    let mut m = bit::Matrix::new("sample.csv").unwrap();
 
     // Compute the sum between given coordinates:
     // (startx, starty) -> (endx, endy)
     let s = m.sum(1, 1, 3, 2);
-    println!("[Allsum method] Sum: {}", s.unwrap())
+    println!("[BIT method] Sum: {}", s.unwrap());
+
+    // Mutate a cell and observe the updated sum
+    m.set(2, 2, 100);
+    let s = m.sum(1, 1, 3, 2);
+    println!("[BIT method] Sum after update: {}", s.unwrap())
 }
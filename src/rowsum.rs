@@ -8,26 +8,162 @@
 //      nothing before).
 //    - Add all these differences to find the final sum
 
+#[cfg(feature = "csv-fallback")]
 use csv;
+use serde::de::DeserializeOwned;
 use serde::Deserialize;
 use std::error::Error;
 use std::fs::File;
+use std::io::{BufRead, BufReader};
+use std::ops::{Add, Mul, Sub};
+use std::str::FromStr;
 
 #[derive(Debug)]
-pub struct Matrix {
+pub struct Matrix<T> {
     ncols: usize,
-    elems: Vec<Row>,
-    presum: Vec<Row>,
+    elems: Vec<Row<T>>,
+    presum: Vec<Row<T>>,
 }
 
 // Row of a matrix containing all the columns within that row.
 // Deserialized from a CSV record.
-#[derive(Debug, Deserialize, Eq, PartialEq, Clone)]
-struct Row {
-    cols: Vec<i64>,
+#[derive(Debug, Deserialize, PartialEq, Clone)]
+struct Row<T> {
+    cols: Vec<T>,
 }
 
-impl Matrix {
+impl<T> Matrix<T>
+where
+    T: Copy + Default + Add<Output = T> + Sub<Output = T> + DeserializeOwned,
+{
+    // sum returns the sum of all the elements of the matrix between the given
+    // coordinates.
+    pub fn sum(&self, startx: usize, starty: usize, endx: usize, endy: usize) -> Result<T, String> {
+        // Validations for input coordinates.
+        //
+        // Because usize means unsigned startx and starty have to be greater
+        // than 0 and hence need not be checked.
+        if endx >= self.ncols {
+            return Err(
+                "endx should be lesser than number of columns {self.ncols-1}, got {endx}"
+                    .to_string(),
+            );
+        }
+        if endy >= self.elems.len() {
+            return Err(format!(
+                "endy should be lesser than number of rows {}, got {endy}",
+                self.elems.len() - 1
+            ));
+        }
+
+        let mut sum: T = T::default();
+        // For each row, read the presum for end column and the one before
+        // start column (0 if start column is 0 because there is nothing
+        // before) and add them up to find the final sum
+        for j in starty..(endy + 1) {
+            let mut start = T::default();
+            if startx != 0 {
+                start = self.presum[j].cols[startx - 1]
+            }
+            sum = sum + (self.presum[j].cols[endx] - start)
+        }
+
+        Ok(sum)
+    }
+
+    // from_elems builds a matrix, and its pre-computed row sums, out of an
+    // already-assembled grid of elements.
+    fn from_elems(elems: Vec<Row<T>>) -> Self {
+        let ncols = elems.first().map_or(0, |r| r.cols.len());
+        let mut presum: Vec<Row<T>> = Vec::with_capacity(elems.len());
+
+        for row in &elems {
+            presum.push(precomp_rowsum(row.clone()))
+        }
+
+        Matrix {
+            ncols: ncols,
+            elems: elems,
+            presum: presum,
+        }
+    }
+}
+
+impl<T> Matrix<T>
+where
+    T: Copy + Default + Add<Output = T> + Sub<Output = T> + DeserializeOwned + FromStr,
+    <T as FromStr>::Err: Error + 'static,
+{
+    // new reads a dense CSV file into a matrix, parsing it directly off a
+    // buffered byte stream instead of going through the `csv` crate. Each
+    // line is read into a single reused buffer via `read_line` (so no fresh
+    // `String` is allocated per row) and split on commas into field slices,
+    // which are parsed with `T::from_str` directly (no intermediate `String`
+    // allocation per field). Each row's parsed values still go into their
+    // own newly allocated `Vec`, which is cloned into `elems` and then
+    // fed straight into `precomp_rowsum` so
+    // `elems` and `presum` are both built in a single pass. This only
+    // handles plain, unquoted fields; build with the `csv-fallback` feature
+    // for quoted or escaped fields.
+    #[cfg(not(feature = "csv-fallback"))]
+    pub fn new(filepath: &str) -> Result<Self, Box<dyn Error>> {
+        let file = File::open(filepath)?;
+        let mut reader = BufReader::new(file);
+
+        let mut elems: Vec<Row<T>> = Vec::new();
+        let mut presum: Vec<Row<T>> = Vec::new();
+
+        let mut ncols = 0;
+        let mut buf = String::new();
+
+        loop {
+            buf.clear();
+            if reader.read_line(&mut buf)? == 0 {
+                break;
+            }
+            let line = buf.trim_end_matches(['\r', '\n']);
+            if line.is_empty() {
+                continue;
+            }
+
+            let mut cols: Vec<T> = Vec::new();
+            for field in line.split(',') {
+                cols.push(field.trim().parse()?);
+            }
+
+            let rl = cols.len();
+
+            // Cross validate that each read row from CSV has the same number
+            // of columns
+            if ncols == 0 {
+                ncols = rl
+            } else if ncols != rl {
+                return Err(format!("each row is expected to have same number of columns, previous rows had {ncols}, this row has {rl}"))?;
+            }
+
+            let row = Row { cols };
+            elems.push(row.clone());
+            presum.push(precomp_rowsum(row))
+        }
+
+        // Return the initialized matrix with its pre-computed sums
+        Ok(Matrix {
+            ncols: ncols,
+            elems: elems,
+            presum: presum,
+        })
+    }
+}
+
+impl<T> Matrix<T>
+where
+    T: Copy + Default + Add<Output = T> + Sub<Output = T> + DeserializeOwned,
+{
+    // new reads the matrix from a CSV file given as input, going through the
+    // `csv` crate so quoted and escaped fields are handled correctly. Build
+    // with the `csv-fallback` feature to use this path instead of the
+    // allocation-light byte scanner.
+    #[cfg(feature = "csv-fallback")]
     pub fn new(filepath: &str) -> Result<Self, Box<dyn Error>> {
         // Read the CSV file record-by-record
         let file = File::open(filepath)?;
@@ -37,14 +173,14 @@ impl Matrix {
             .from_reader(file);
 
         // Initialize the main matrix and the pre-computed sum matrix
-        let mut elems: Vec<Row> = Vec::new();
-        let mut presum: Vec<Row> = Vec::new();
+        let mut elems: Vec<Row<T>> = Vec::new();
+        let mut presum: Vec<Row<T>> = Vec::new();
 
         let mut ncols = 0;
 
         // Read and deserialize each row from the CSV
         for result in rdr.deserialize() {
-            let row: Row = result?;
+            let row: Row<T> = result?;
             let rl = row.cols.len();
 
             // Push the read row (it's clone actually due to Rust semantics)
@@ -72,46 +208,271 @@ impl Matrix {
             presum: presum,
         })
     }
+}
 
-    // sum returns the sum of all the elements of the matrix between the given
-    // coordinates.
-    pub fn sum(
-        &self,
-        startx: usize,
-        starty: usize,
-        endx: usize,
-        endy: usize,
-    ) -> Result<i64, String> {
-        // Validations for input coordinates.
-        //
-        // Because usize means unsigned startx and starty have to be greater
-        // than 0 and hence need not be checked.
-        if endx >= self.ncols {
-            return Err(
-                "endx should be lesser than number of columns {self.ncols-1}, got {endx}"
-                    .to_string(),
+// Add combines two matrices cell-by-cell and rebuilds the presum table for
+// the result. The two matrices must have the same dimensions.
+impl<T> Add for Matrix<T>
+where
+    T: Copy + Default + Add<Output = T> + Sub<Output = T> + DeserializeOwned,
+{
+    type Output = Matrix<T>;
+
+    fn add(self, rhs: Matrix<T>) -> Matrix<T> {
+        if self.elems.len() != rhs.elems.len() || self.ncols != rhs.ncols {
+            panic!(
+                "cannot add matrices of different dimensions: {}x{} vs {}x{}",
+                self.elems.len(),
+                self.ncols,
+                rhs.elems.len(),
+                rhs.ncols
             );
         }
-        if endy >= self.elems.len() {
-            return Err(format!(
-                "endy should be lesser than number of rows {}, got {endy}",
-                self.elems.len() - 1
-            ));
+
+        let elems = self
+            .elems
+            .iter()
+            .zip(rhs.elems.iter())
+            .map(|(a, b)| Row {
+                cols: a
+                    .cols
+                    .iter()
+                    .zip(b.cols.iter())
+                    .map(|(&x, &y)| x + y)
+                    .collect(),
+            })
+            .collect();
+
+        Matrix::from_elems(elems)
+    }
+}
+
+// Sub combines two matrices cell-by-cell and rebuilds the presum table for
+// the result. The two matrices must have the same dimensions.
+impl<T> Sub for Matrix<T>
+where
+    T: Copy + Default + Add<Output = T> + Sub<Output = T> + DeserializeOwned,
+{
+    type Output = Matrix<T>;
+
+    fn sub(self, rhs: Matrix<T>) -> Matrix<T> {
+        if self.elems.len() != rhs.elems.len() || self.ncols != rhs.ncols {
+            panic!(
+                "cannot subtract matrices of different dimensions: {}x{} vs {}x{}",
+                self.elems.len(),
+                self.ncols,
+                rhs.elems.len(),
+                rhs.ncols
+            );
         }
 
-        let mut sum: i64 = 0;
-        // For each row, read the presum for end column and the one before
-        // start column (0 if start column is 0 because there is nothing
-        // before) and add them up to find the final sum
-        for j in starty..(endy + 1) {
-            let mut start = 0;
-            if startx != 0 {
-                start = self.presum[j].cols[startx - 1]
+        let elems = self
+            .elems
+            .iter()
+            .zip(rhs.elems.iter())
+            .map(|(a, b)| Row {
+                cols: a
+                    .cols
+                    .iter()
+                    .zip(b.cols.iter())
+                    .map(|(&x, &y)| x - y)
+                    .collect(),
+            })
+            .collect();
+
+        Matrix::from_elems(elems)
+    }
+}
+
+// Mul performs standard matrix multiplication: the inner dimension of self
+// (its number of columns) must equal the number of rows of rhs.
+impl<T> Mul for Matrix<T>
+where
+    T: Copy + Default + Add<Output = T> + Sub<Output = T> + Mul<Output = T> + DeserializeOwned,
+{
+    type Output = Matrix<T>;
+
+    fn mul(self, rhs: Matrix<T>) -> Matrix<T> {
+        if self.ncols != rhs.elems.len() {
+            panic!(
+                "cannot multiply a matrix with {} columns by a matrix with {} rows",
+                self.ncols,
+                rhs.elems.len()
+            );
+        }
+
+        let nrows = self.elems.len();
+        let ncols = rhs.ncols;
+
+        let mut elems: Vec<Row<T>> = Vec::with_capacity(nrows);
+        for i in 0..nrows {
+            let mut cols: Vec<T> = Vec::with_capacity(ncols);
+            for k in 0..ncols {
+                let mut sum = T::default();
+                for j in 0..self.ncols {
+                    sum = sum + self.elems[i].cols[j] * rhs.elems[j].cols[k]
+                }
+                cols.push(sum)
             }
-            sum += self.presum[j].cols[endx] - start
+            elems.push(Row { cols })
         }
 
-        Ok(sum)
+        Matrix::from_elems(elems)
+    }
+}
+
+impl<T> Matrix<T>
+where
+    T: Copy + Default + Add<Output = T> + Sub<Output = T> + DeserializeOwned + FromStr,
+    <T as FromStr>::Err: Error + 'static,
+{
+    // from_matrix_market reads a matrix in the Matrix Market (.mtx) exchange
+    // format, in either dense `array` or sparse `coordinate` form, and builds
+    // the same pre-computed sum table as `new`.
+    pub fn from_matrix_market(filepath: &str) -> Result<Self, Box<dyn Error>> {
+        let file = File::open(filepath)?;
+        let mut lines = BufReader::new(file).lines();
+
+        let banner = lines.next().ok_or("matrix market file is empty")??;
+        let fields: Vec<&str> = banner.trim().split_whitespace().collect();
+        if fields.len() != 5 || fields[0] != "%%MatrixMarket" || fields[1] != "matrix" {
+            return Err(format!("malformed Matrix Market banner: {banner}"))?;
+        }
+        let kind = fields[2];
+        if kind != "coordinate" && kind != "array" {
+            return Err(format!("unsupported Matrix Market format: {kind}"))?;
+        }
+        let symmetric = fields[4] == "symmetric";
+
+        // Skip `%`-prefixed comment lines and blank lines to find the size
+        // line.
+        let mut size_line = None;
+        for line in &mut lines {
+            let line = line?;
+            let trimmed = line.trim();
+            if trimmed.is_empty() || trimmed.starts_with('%') {
+                continue;
+            }
+            size_line = Some(trimmed.to_string());
+            break;
+        }
+        let size_line = size_line.ok_or("matrix market file is missing a size line")?;
+        let dims: Vec<usize> = size_line
+            .split_whitespace()
+            .map(|s| s.parse::<usize>())
+            .collect::<Result<_, _>>()?;
+
+        let mut grid: Vec<Vec<T>>;
+
+        if kind == "array" {
+            if dims.len() != 2 {
+                return Err(format!(
+                    "array size line should have 2 fields, got {size_line}"
+                ))?;
+            }
+            let (nrows, ncols) = (dims[0], dims[1]);
+            if symmetric && nrows != ncols {
+                return Err(format!(
+                    "symmetric array requires a square matrix, got {nrows}x{ncols}"
+                ))?;
+            }
+            grid = vec![vec![T::default(); ncols]; nrows];
+            let expected = if symmetric {
+                nrows * (nrows + 1) / 2
+            } else {
+                nrows * ncols
+            };
+
+            // For the `symmetric` array form, Matrix Market stores only the
+            // lower triangle (including the diagonal) in column-major order,
+            // so walk column-by-column from the diagonal down and mirror
+            // each off-diagonal entry. The general form stores every entry
+            // in column-major order, so a linear `count` is enough.
+            let mut count = 0;
+            let mut row = 0;
+            let mut col = 0;
+            for line in &mut lines {
+                let line = line?;
+                let trimmed = line.trim();
+                if trimmed.is_empty() {
+                    continue;
+                }
+                if count >= expected {
+                    return Err(format!(
+                        "array body should have {expected} values, got more than that"
+                    ))?;
+                }
+                let value: T = trimmed.parse()?;
+                if symmetric {
+                    grid[row][col] = value;
+                    if row != col {
+                        grid[col][row] = value;
+                    }
+                    row += 1;
+                    if row == nrows {
+                        col += 1;
+                        row = col;
+                    }
+                } else {
+                    let r = count % nrows;
+                    let c = count / nrows;
+                    grid[r][c] = value;
+                }
+                count += 1;
+            }
+            if count != expected {
+                return Err(format!(
+                    "array body should have {expected} values, got {count}"
+                ))?;
+            }
+        } else {
+            if dims.len() != 3 {
+                return Err(format!(
+                    "coordinate size line should have 3 fields, got {size_line}"
+                ))?;
+            }
+            let (nrows, ncols, nnz) = (dims[0], dims[1], dims[2]);
+            grid = vec![vec![T::default(); ncols]; nrows];
+
+            let mut count = 0;
+            for line in &mut lines {
+                let line = line?;
+                let trimmed = line.trim();
+                if trimmed.is_empty() {
+                    continue;
+                }
+                let parts: Vec<&str> = trimmed.split_whitespace().collect();
+                if parts.len() < 3 {
+                    return Err(format!("malformed coordinate entry: {trimmed}"))?;
+                }
+                let row: usize = parts[0].parse()?;
+                let col: usize = parts[1].parse()?;
+                let value: T = parts[2].parse()?;
+
+                if row == 0 || row > nrows || col == 0 || col > ncols {
+                    return Err(format!(
+                        "coordinate ({row}, {col}) out of range for a {nrows}x{ncols} matrix"
+                    ))?;
+                }
+
+                grid[row - 1][col - 1] = value;
+                if symmetric && row != col {
+                    grid[col - 1][row - 1] = value;
+                }
+                count += 1;
+            }
+            if count != nnz {
+                return Err(format!(
+                    "coordinate body should have {nnz} entries, got {count}"
+                ))?;
+            }
+        }
+
+        // Run the dense grid through the same precompute machinery as a
+        // CSV-loaded matrix so queries behave identically.
+        let elems: Vec<Row<T>> = grid.drain(..).map(|cols| Row { cols }).collect();
+
+        Ok(Matrix::from_elems(elems))
     }
 }
 
@@ -120,15 +481,18 @@ impl Matrix {
 // in column 3 is the sum of all the values from columns 0, 1, 2, and 3
 // I.e. for the row [1, 2, 5, 11], column 3 has 19 in the rowsum
 // returned.
-fn precomp_rowsum(row: Row) -> Row {
+fn precomp_rowsum<T>(row: Row<T>) -> Row<T>
+where
+    T: Copy + Default + Add<Output = T>,
+{
     let ncols = row.cols.len();
     let mut rowsum = Row {
         cols: Vec::with_capacity(ncols),
     };
-    let mut sum: i64 = 0;
+    let mut sum: T = T::default();
 
     for ci in 0..ncols {
-        sum += row.cols[ci];
+        sum = sum + row.cols[ci];
         rowsum.cols.push(sum)
     }
     rowsum